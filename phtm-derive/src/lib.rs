@@ -0,0 +1,296 @@
+//! The companion proc-macro crate for `phtm`.
+//!
+//! RFC 738 makes an unused generic type/lifetime parameter a hard
+//! error, which is the entire reason to reach for `phtm` in the
+//! first place. [`Phantom`] scans a struct's generics, finds every
+//! parameter not already constrained by a real field, and injects a
+//! single hidden `PhantomData` field covering all of them.
+//!
+//! This is a `#[proc_macro_attribute]`, not a `#[proc_macro_derive]`,
+//! even though the feature reads like a derive: a derive macro can
+//! only *add* an impl alongside a struct, it cannot add a field to
+//! the struct itself, which is exactly what's needed here.
+//!
+//! Variance is chosen per parameter with a `#[phantom(..)]`
+//! attribute, defaulting to invariant (the safe choice) when
+//! unannotated:
+//!
+//! ```ignore
+//! use phtm::Phantom;
+//!
+//! #[Phantom]
+//! struct Iter<#[phantom(covariant)] 'a, #[phantom(covariant)] T: 'a> {
+//!     ptr: *const T,
+//!     end: *const T,
+//! }
+//! ```
+//!
+//! Recognized parameter attributes are `#[phantom(covariant)]`,
+//! `#[phantom(contravariant)]`, `#[phantom(invariant)]` and, for
+//! type parameters only, `#[phantom(owns)]`. A struct-level
+//! `#[phantom(not_send)]` and/or `#[phantom(not_sync)]` additionally
+//! append `NotSendOrSync`/`NotSync` to the generated field.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, visit::Visit, Data, DeriveInput, Fields,
+    GenericParam, Ident, Lifetime, Path, Token, Type,
+};
+
+/// See the [crate documentation](index.html).
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn Phantom(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(args).into_iter().next().unwrap().span(),
+            "`#[Phantom]` does not take any arguments; put per-parameter \
+             configuration on the generic parameters themselves",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let mut item = parse_macro_input!(input as DeriveInput);
+    match expand(&mut item) {
+        Ok(()) => quote!(#item).into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn expand(input: &mut DeriveInput) -> syn::Result<()> {
+    let not_send_not_sync = take_container_markers(&mut input.attrs)?;
+
+    let fields = match &mut input.data {
+        Data::Struct(data) => match &mut data.fields {
+            Fields::Named(fields) => &mut fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`#[Phantom]` only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[Phantom]` only supports structs",
+            ))
+        }
+    };
+
+    let mut markers = Vec::new();
+    for param in &mut input.generics.params {
+        let (name, span, kind, attrs) = match param {
+            GenericParam::Lifetime(lt) => (
+                lt.lifetime.ident.to_string(),
+                lt.lifetime.ident.span(),
+                ParamKind::Lifetime,
+                &mut lt.attrs,
+            ),
+            GenericParam::Type(ty) => {
+                (ty.ident.to_string(), ty.ident.span(), ParamKind::Type, &mut ty.attrs)
+            }
+            GenericParam::Const(_) => continue,
+        };
+
+        let variance = take_variance_attr(attrs)?;
+        if is_used_in_fields(&name, kind, fields) {
+            continue;
+        }
+        markers.push(marker_type(&name, span, kind, variance.unwrap_or(Variance::Invariant))?);
+    }
+
+    if let Some(marker) = not_send_or_sync_type(not_send_not_sync) {
+        markers.push(marker);
+    }
+
+    if markers.is_empty() {
+        return Ok(());
+    }
+
+    let field_name = unused_field_name(fields);
+    fields.push(syn::Field {
+        attrs: vec![],
+        vis: syn::Visibility::Inherited,
+        mutability: syn::FieldMutability::None,
+        ident: Some(field_name),
+        colon_token: Some(Default::default()),
+        ty: Type::Verbatim(quote! { ::phtm::PhantomData<(#(#markers,)*)> }),
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParamKind {
+    Lifetime,
+    Type,
+}
+
+#[derive(Clone, Copy)]
+enum Variance {
+    Covariant,
+    Contravariant,
+    Invariant,
+    Owns,
+}
+
+/// Check for whether `name` is mentioned anywhere in the struct's
+/// real field types, by walking the parsed [`Type`] of each field
+/// rather than its raw tokens — so a field merely *named* `name`, or
+/// a qualified path whose last segment happens to match `name` (e.g.
+/// `other::T` when looking for the type parameter `T`), isn't
+/// mistaken for a use of the parameter.
+fn is_used_in_fields(name: &str, kind: ParamKind, fields: &Punctuated<syn::Field, Token![,]>) -> bool {
+    struct Finder<'a> {
+        name: &'a str,
+        kind: ParamKind,
+        found: bool,
+    }
+
+    impl<'ast> Visit<'ast> for Finder<'_> {
+        fn visit_path(&mut self, path: &'ast Path) {
+            if self.kind == ParamKind::Type
+                && path.leading_colon.is_none()
+                && path.segments.len() == 1
+                && path.segments[0].ident == self.name
+            {
+                self.found = true;
+            }
+            syn::visit::visit_path(self, path);
+        }
+
+        fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+            if self.kind == ParamKind::Lifetime && lifetime.ident == self.name {
+                self.found = true;
+            }
+        }
+    }
+
+    fields.iter().any(|field| {
+        let mut finder = Finder { name, kind, found: false };
+        finder.visit_type(&field.ty);
+        finder.found
+    })
+}
+
+fn take_variance_attr(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<Variance>> {
+    let mut found = None;
+    let mut err = None;
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("phantom") || err.is_some() {
+            return true;
+        }
+        let result = attr.parse_nested_meta(|meta| {
+            found = Some(if meta.path.is_ident("covariant") {
+                Variance::Covariant
+            } else if meta.path.is_ident("contravariant") {
+                Variance::Contravariant
+            } else if meta.path.is_ident("invariant") {
+                Variance::Invariant
+            } else if meta.path.is_ident("owns") {
+                Variance::Owns
+            } else {
+                return Err(meta.error("unrecognized `#[phantom(..)]` clause"));
+            });
+            Ok(())
+        });
+        if let Err(e) = result {
+            err = Some(e);
+        }
+        false
+    });
+    match err {
+        Some(e) => Err(e),
+        None => Ok(found),
+    }
+}
+
+fn take_container_markers(attrs: &mut Vec<syn::Attribute>) -> syn::Result<(bool, bool)> {
+    let mut not_send = false;
+    let mut not_sync = false;
+    let mut err = None;
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("phantom") || err.is_some() {
+            return true;
+        }
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("not_send") {
+                not_send = true;
+            } else if meta.path.is_ident("not_sync") {
+                not_sync = true;
+            } else {
+                return Err(meta.error("unrecognized `#[phantom(..)]` clause"));
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            err = Some(e);
+        }
+        false
+    });
+    match err {
+        Some(e) => Err(e),
+        None => Ok((not_send, not_sync)),
+    }
+}
+
+fn not_send_or_sync_type((not_send, not_sync): (bool, bool)) -> Option<proc_macro2::TokenStream> {
+    match (not_send, not_sync) {
+        (true, _) => Some(quote! { ::phtm::NotSendOrSync }),
+        (false, true) => Some(quote! { ::phtm::NotSync }),
+        (false, false) => None,
+    }
+}
+
+fn marker_type(name: &str, span: Span, kind: ParamKind, variance: Variance) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match (kind, variance) {
+        (ParamKind::Type, Variance::Covariant) => {
+            let ident = Ident::new(name, span);
+            quote! { ::phtm::CovariantOver<#ident> }
+        }
+        (ParamKind::Type, Variance::Contravariant) => {
+            let ident = Ident::new(name, span);
+            quote! { ::phtm::ContravariantOver<#ident> }
+        }
+        (ParamKind::Type, Variance::Invariant) => {
+            let ident = Ident::new(name, span);
+            quote! { ::phtm::InvariantOver<#ident> }
+        }
+        (ParamKind::Type, Variance::Owns) => {
+            let ident = Ident::new(name, span);
+            quote! { ::phtm::Owns<#ident> }
+        }
+        (ParamKind::Lifetime, Variance::Covariant) => {
+            let lt = syn::Lifetime::new(&format!("'{name}"), span);
+            quote! { ::phtm::CovariantOverLt<#lt> }
+        }
+        (ParamKind::Lifetime, Variance::Contravariant) => {
+            let lt = syn::Lifetime::new(&format!("'{name}"), span);
+            quote! { ::phtm::ContraVariantOverLt<#lt> }
+        }
+        (ParamKind::Lifetime, Variance::Invariant) => {
+            let lt = syn::Lifetime::new(&format!("'{name}"), span);
+            quote! { ::phtm::InvariantOverLt<#lt> }
+        }
+        (ParamKind::Lifetime, Variance::Owns) => {
+            return Err(syn::Error::new(
+                span,
+                "`#[phantom(owns)]` is not meaningful for a lifetime parameter",
+            ))
+        }
+    })
+}
+
+/// Picks a field name that can't collide with a real, user-written
+/// field, by adding leading underscores until it is unique.
+fn unused_field_name(fields: &Punctuated<syn::Field, Token![,]>) -> Ident {
+    let mut name = "_phantom_data".to_string();
+    while fields.iter().any(|f| f.ident.as_ref().is_some_and(|i| i == &name)) {
+        name.insert(0, '_');
+    }
+    Ident::new(&name, Span::call_site())
+}
@@ -0,0 +1,10 @@
+//! UI tests for `#[Phantom]`'s happy path and error paths, driven by
+//! `trybuild` since these are compile-time behaviors of a proc-macro
+//! attribute.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,10 @@
+//! `#[Phantom]` only supports structs.
+
+use phtm::Phantom;
+
+#[Phantom]
+enum Foo<T> {
+    A(T),
+}
+
+fn main() {}
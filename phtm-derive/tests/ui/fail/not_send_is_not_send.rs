@@ -0,0 +1,15 @@
+//! `#[phantom(not_send)]` must actually suppress the auto `Send` impl.
+
+use phtm::Phantom;
+
+#[Phantom]
+#[phantom(not_send)]
+struct NotSendThing<T> {
+    _x: T,
+}
+
+fn assert_send<T: Send>() {}
+
+fn main() {
+    assert_send::<NotSendThing<i32>>();
+}
@@ -0,0 +1,12 @@
+//! `#[phantom(owns)]` isn't meaningful on a lifetime parameter. Must
+//! stay unused in the fields, otherwise `#[Phantom]` never needs to
+//! pick a marker for it at all.
+
+use phtm::Phantom;
+
+#[Phantom]
+struct Foo<#[phantom(owns)] 'a> {
+    _x: (),
+}
+
+fn main() {}
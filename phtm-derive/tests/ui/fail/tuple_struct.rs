@@ -0,0 +1,8 @@
+//! `#[Phantom]` only supports structs with named fields.
+
+use phtm::Phantom;
+
+#[Phantom]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! An unrecognized `#[phantom(..)]` clause is a compile error, not a
+//! silent no-op.
+
+use phtm::Phantom;
+
+#[Phantom]
+struct Foo<#[phantom(sideways)] T> {
+    _x: T,
+}
+
+fn main() {}
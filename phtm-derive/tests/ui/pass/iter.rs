@@ -0,0 +1,17 @@
+//! The nomicon `Iter<'a, T>` shape: covariant over both `'a` and `T`.
+
+use phtm::Phantom;
+
+#[Phantom]
+struct Iter<#[phantom(covariant)] 'a, #[phantom(covariant)] T: 'a> {
+    ptr: *const T,
+    end: *const T,
+}
+
+fn assert_covariant<'long: 'short, 'short, T>(x: Iter<'long, T>) -> Iter<'short, T> {
+    x
+}
+
+fn main() {
+    let _ = assert_covariant::<()>;
+}
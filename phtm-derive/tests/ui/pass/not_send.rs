@@ -0,0 +1,14 @@
+//! `#[phantom(not_send)]` compiles and the generated field doesn't
+//! collide with the real one.
+
+use phtm::Phantom;
+
+#[Phantom]
+#[phantom(not_send)]
+struct NotSendThing<T> {
+    _x: T,
+}
+
+fn main() {
+    let _ = NotSendThing::<i32> { _x: 0, _phantom_data: Default::default() };
+}
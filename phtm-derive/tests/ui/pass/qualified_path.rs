@@ -0,0 +1,18 @@
+//! A field whose type is a qualified path ending in a segment that
+//! happens to match the generic parameter's name must not be mistaken
+//! for a use of that parameter.
+
+use phtm::Phantom;
+
+mod other {
+    pub struct T;
+}
+
+#[Phantom]
+struct Foo<T> {
+    field: other::T,
+}
+
+fn main() {
+    let _ = Foo::<()> { field: other::T, _phantom_data: Default::default() };
+}
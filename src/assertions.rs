@@ -0,0 +1,114 @@
+//! Compile-time variance assertions: [`assert_covariant!`] and
+//! [`assert_contravariant!`].
+//!
+//! There is deliberately no `assert_invariant!`: invariance can't be
+//! expressed as "this coercion compiles" the way covariance and
+//! contravariance are, since the whole point is that *no* coercion
+//! should be allowed, and rustc's region inference unifies lifetimes
+//! flexibly enough during trait selection that a trait-equality
+//! encoding comes back ambiguous (`E0283`) for every type, not just
+//! non-invariant ones. Assert the *absence* of covariance and
+//! contravariance with a pair of `compile_fail` doctests using
+//! [`assert_covariant!`] and [`assert_contravariant!`] instead.
+
+/// Assert that a type is covariant, either over a lifetime or over
+/// a type parameter.
+///
+/// Because variance is *inferred* from the types of a struct's
+/// fields (per [RFC 738]), swapping e.g. [`CovariantOver<T>`] for
+/// [`InvariantOver<T>`] compiles fine and silently changes what
+/// coercions the type supports. This macro regression-tests the
+/// variance a type was meant to have.
+///
+/// `assert_covariant!(Foo<'_>)` asserts covariance over `Foo`'s
+/// lifetime parameter. It expands to a function that only compiles
+/// if a `Foo` with a longer lifetime coerces to a `Foo` with a
+/// shorter one:
+///
+/// ```
+/// use phtm::{assert_covariant, CovariantOver};
+///
+/// struct Foo<'a>(CovariantOver<&'a ()>);
+///
+/// assert_covariant!(Foo<'_>);
+/// ```
+///
+/// `assert_covariant!(Foo<T>)` instead asserts covariance over the
+/// type parameter `T`, by substituting `&'long T`/`&'short T` for it:
+///
+/// ```
+/// use phtm::{assert_covariant, CovariantOver};
+///
+/// struct Foo<T>(CovariantOver<T>);
+///
+/// assert_covariant!(Foo<T>);
+/// ```
+///
+/// [RFC 738]: https://rust-lang.github.io/rfcs/0738-variance.html
+/// [`CovariantOver<T>`]: crate::CovariantOver
+/// [`InvariantOver<T>`]: crate::InvariantOver
+#[macro_export]
+macro_rules! assert_covariant {
+    ($ty:ident<'_>) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __assert_covariant<'long: 'short, 'short>(x: $ty<'long>) -> $ty<'short> {
+                x
+            }
+        };
+    };
+    ($ty:ident<$t:ident>) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __assert_covariant<'long: 'short, 'short, $t>(
+                x: $ty<&'long $t>,
+            ) -> $ty<&'short $t> {
+                x
+            }
+        };
+    };
+}
+
+/// Assert that a type is contravariant, either over a lifetime or
+/// over a type parameter.
+///
+/// This is the mirror image of [`assert_covariant!`]: it expands to
+/// a function that only compiles if a `Foo` with a *shorter*
+/// lifetime coerces to a `Foo` with a longer one.
+///
+/// ```
+/// use phtm::{assert_contravariant, ContraVariantOverLt};
+///
+/// struct Foo<'a>(ContraVariantOverLt<'a>);
+///
+/// assert_contravariant!(Foo<'_>);
+/// ```
+///
+/// ```
+/// use phtm::{assert_contravariant, ContravariantOver};
+///
+/// struct Foo<T>(ContravariantOver<T>);
+///
+/// assert_contravariant!(Foo<T>);
+/// ```
+#[macro_export]
+macro_rules! assert_contravariant {
+    ($ty:ident<'_>) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __assert_contravariant<'long: 'short, 'short>(x: $ty<'short>) -> $ty<'long> {
+                x
+            }
+        };
+    };
+    ($ty:ident<$t:ident>) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __assert_contravariant<'long: 'short, 'short, $t>(
+                x: $ty<&'short $t>,
+            ) -> $ty<&'long $t> {
+                x
+            }
+        };
+    };
+}
@@ -150,12 +150,30 @@
 //! change, while explicitly adding the marker types allows future
 //! possibilities of having single-threaded containers without
 //! bumping the major version.
+//!
+//! # Composing requirements
+//!
+//! A type is rarely covariant over just one parameter; it is common
+//! to need, say, covariance over `T`, invariance over `U`, and
+//! `!Send` all at once. Declaring one field per requirement (or
+//! hand-rolling a tuple of the aliases above) is exactly the
+//! bookkeeping this crate exists to avoid. The [`phantom!`] macro
+//! fuses any combination of the requirements above into a single
+//! `PhantomData` field; see its documentation for the full clause
+//! list.
+//!
+//! With the `derive` feature enabled, [`Phantom`] goes one step
+//! further and picks the field (and its variance, via per-parameter
+//! attributes) for you, straight from a struct's generics.
 
 #![cfg_attr(not(doc), no_std)] // intra doc links need std
 #![forbid(unsafe_code)]
 #![deny(warnings, clippy::all, rust_2018_idioms, future_incompatible)]
 #![deny(rustdoc::broken_intra_doc_links, missing_docs)]
 
+mod assertions;
+mod macros;
+
 use core::cell::Cell;
 
 #[doc(no_inline)]
@@ -164,6 +182,15 @@ pub use core::marker::PhantomData;
 #[doc(no_inline)]
 pub use core::marker::PhantomPinned;
 
+/// Inject the right [`PhantomData`] field for a struct's unused
+/// generics automatically.
+///
+/// Requires the `derive` feature. See the [`phtm_derive`] crate
+/// documentation for the full attribute syntax.
+#[cfg(feature = "derive")]
+#[doc(no_inline)]
+pub use phtm_derive::Phantom;
+
 /// Verbose version of `PhantomData`.
 /// 
 /// It is covariant over `T` with drop checking.
@@ -195,6 +222,34 @@ pub type ImmutablyReferences<'a, T> = PhantomData<&'a T>;
 /// [crate root documentation]: index.html
 pub type MutablyReferences<'a, T> = PhantomData<&'a mut T>;
 
+/// Alias for `PhantomData<fn() -> &'a T>`. `'a` and `T` are both
+/// covariant, same as [`ImmutablyReferences`] — but unlike
+/// `ImmutablyReferences`, this does not require `T: 'a` and does
+/// not use drop check, since a `fn() -> &'a T` never actually holds
+/// on to a `T`.
+///
+/// Use this instead of [`ImmutablyReferences`] when you need the
+/// variance of a reference without the implied outlives bound, for
+/// example when porting code that must not trigger dropck.
+///
+/// See the [crate root documentation] for details on variance
+/// and drop checking.
+///
+/// [crate root documentation]: index.html
+pub type WeaklyReferences<'a, T> = PhantomData<fn() -> &'a T>;
+
+/// Alias for `PhantomData<fn(&'a T)>`. Both `'a` and `T` are
+/// contravariant, and, like [`WeaklyReferences`], this does not use
+/// drop check, since a `fn(&'a T)` never actually holds on to a `T`.
+/// It does not affect [`Send`] or [`Sync`] either, since a bare `fn`
+/// pointer is always both regardless of its argument types.
+///
+/// See the [crate root documentation] for details on variance
+/// and drop checking.
+///
+/// [crate root documentation]: index.html
+pub type ContravariantlyReferences<'a, T> = PhantomData<fn(&'a T)>;
+
 /// See the [crate root documentation] for details on variance.
 ///
 /// [crate root documentation]: index.html
@@ -227,7 +282,7 @@ pub type CovariantOver<T> = PhantomData<fn() -> T>;
 /// See the [crate root documentation] for details on variance.
 ///
 /// [crate root documentation]: index.html
-pub type CovariantOverLt<'co> = PhantomData<ContravariantOver<&'co ()>>;
+pub type CovariantOverLt<'co> = CovariantOver<&'co ()>;
 
 /// Marks a type as contravariant.
 ///
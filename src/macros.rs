@@ -0,0 +1,100 @@
+//! The [`phantom!`](crate::phantom) composition macro and its
+//! internal tt-munchers.
+
+/// Fuse several variance/marker requirements into a single
+/// [`PhantomData`] field.
+///
+/// Spelling out `CovariantOver<T>`, `InvariantOver<U>` and
+/// `NotSync` as separate fields (or hand-rolling a tuple of them)
+/// is exactly the bookkeeping this crate exists to avoid once more
+/// than one requirement is in play. `phantom!` accepts a
+/// comma-separated list of clauses and expands to a single
+/// `PhantomData` over a tuple of the corresponding aliases:
+///
+/// - `covariant(T)` — [`CovariantOver<T>`](crate::CovariantOver)
+/// - `contravariant(T)` — [`ContravariantOver<T>`](crate::ContravariantOver)
+/// - `invariant(T)` — [`InvariantOver<T>`](crate::InvariantOver)
+/// - `covariant_lt('a)` — [`CovariantOverLt<'a>`](crate::CovariantOverLt)
+/// - `invariant_lt('a)` — [`InvariantOverLt<'a>`](crate::InvariantOverLt)
+/// - `owns(T)` — [`Owns<T>`](crate::Owns)
+/// - `!Send` — [`NotSendOrSync`](crate::NotSendOrSync)
+/// - `!Sync` — [`NotSync`](crate::NotSync)
+/// - `!Send, !Sync` (in either order) — [`NotSendOrSync`](crate::NotSendOrSync)
+///
+/// It can be used in type position or in value position, since
+/// `PhantomData` is itself a unit struct:
+///
+/// ```
+/// use phtm::phantom;
+///
+/// type Marker<'a, T, U> = phantom!(covariant_lt('a), covariant(T), invariant(U), !Send);
+///
+/// struct Foo<'a, T, U> {
+///     _pd: Marker<'a, T, U>,
+/// }
+///
+/// impl<'a, T, U> Foo<'a, T, U> {
+///     fn new() -> Self {
+///         Self { _pd: phantom!(covariant_lt('a), covariant(T), invariant(U), !Send) }
+///     }
+/// }
+/// ```
+///
+/// See the [crate root documentation](index.html) for what each
+/// clause means.
+#[macro_export]
+macro_rules! phantom {
+    ($($clauses:tt)*) => {
+        $crate::PhantomData::<
+            $crate::__phantom_munch!(@acc [] @send(false) @sync(false) $($clauses)*)
+        >
+    };
+}
+
+/// Implementation detail of [`phantom!`](crate::phantom). Not public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __phantom_munch {
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt)) => {
+        $crate::__phantom_finish!([$($ty,)*] $send $sync)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) covariant($t:ty) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::CovariantOver<$t>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) contravariant($t:ty) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::ContravariantOver<$t>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) invariant($t:ty) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::InvariantOver<$t>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) owns($t:ty) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::Owns<$t>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) covariant_lt($lt:lifetime) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::CovariantOverLt<$lt>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) invariant_lt($lt:lifetime) $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)* $crate::InvariantOverLt<$lt>,] @send($send) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) ! Send $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)*] @send(true) @sync($sync) $($($rest)*)?)
+    };
+    (@acc [$($ty:ty,)*] @send($send:tt) @sync($sync:tt) ! Sync $(, $($rest:tt)*)?) => {
+        $crate::__phantom_munch!(@acc [$($ty,)*] @send($send) @sync(true) $($($rest)*)?)
+    };
+}
+
+/// Implementation detail of [`phantom!`](crate::phantom). Not public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __phantom_finish {
+    ([$($ty:ty,)*] true $sync:tt) => {
+        ($($ty,)* $crate::NotSendOrSync,)
+    };
+    ([$($ty:ty,)*] false true) => {
+        ($($ty,)* $crate::NotSync,)
+    };
+    ([$($ty:ty,)*] false false) => {
+        ($($ty,)*)
+    };
+}